@@ -0,0 +1,162 @@
+use crate::TextureAtlasSprite;
+use bevy_core::Time;
+use bevy_ecs::prelude::*;
+use std::ops::Range;
+
+/// What a [`SpriteSheetAnimation`] does once it reaches the end of its frame sequence
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnimationFinishBehavior {
+    /// Start again from the first frame
+    Loop,
+    /// Play forward then backward, bouncing between the first and last frame
+    PingPong,
+    /// Stop on the last frame
+    Hold,
+    /// Despawn the entity the animation is attached to
+    Despawn,
+}
+
+impl Default for AnimationFinishBehavior {
+    fn default() -> Self {
+        AnimationFinishBehavior::Loop
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PlaybackState {
+    Playing,
+    Paused,
+}
+
+/// Plays an ordered sequence of [`TextureAtlas`](crate::TextureAtlas) frames on an entity's
+/// [`TextureAtlasSprite`] over time.
+///
+/// Advanced each frame by [`sprite_sheet_animation_system`], which the sprite plugin registers.
+#[derive(Debug, Clone)]
+pub struct SpriteSheetAnimation {
+    frames: Vec<u32>,
+    frame_duration: f32,
+    on_finish: AnimationFinishBehavior,
+    state: PlaybackState,
+    current_frame: usize,
+    playing_in_reverse: bool,
+    timer: f32,
+}
+
+impl SpriteSheetAnimation {
+    /// Creates an animation that plays `frames` in order, holding each one for `frame_duration`
+    /// seconds, looping by default
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frames` is empty or `frame_duration` is not a positive number.
+    pub fn new(frames: Vec<u32>, frame_duration: f32) -> Self {
+        assert!(!frames.is_empty(), "SpriteSheetAnimation must have at least one frame");
+        assert!(
+            frame_duration > 0.0,
+            "SpriteSheetAnimation frame_duration must be greater than zero"
+        );
+
+        Self {
+            frames,
+            frame_duration,
+            on_finish: AnimationFinishBehavior::default(),
+            state: PlaybackState::Playing,
+            current_frame: 0,
+            playing_in_reverse: false,
+            timer: 0.0,
+        }
+    }
+
+    /// Creates an animation that plays every index in `range`, in order. Handy for animating a
+    /// contiguous run of frames from a [`TextureAtlas::from_grid`](crate::TextureAtlas::from_grid) sheet.
+    ///
+    /// Panics under the same conditions as [`new`](Self::new).
+    pub fn from_range(range: Range<u32>, frame_duration: f32) -> Self {
+        Self::new(range.collect(), frame_duration)
+    }
+
+    /// Sets the behavior to apply once the animation reaches its last frame
+    pub fn with_on_finish(mut self, on_finish: AnimationFinishBehavior) -> Self {
+        self.on_finish = on_finish;
+        self
+    }
+
+    pub fn play(&mut self) {
+        self.state = PlaybackState::Playing;
+    }
+
+    pub fn pause(&mut self) {
+        self.state = PlaybackState::Paused;
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.state == PlaybackState::Playing
+    }
+
+    /// The atlas frame index the animation is currently on
+    pub fn current_frame(&self) -> u32 {
+        self.frames[self.current_frame]
+    }
+
+    fn advance(&mut self, commands: &mut Commands, entity: Entity) {
+        let last_frame = self.frames.len() - 1;
+
+        if self.playing_in_reverse {
+            if self.current_frame == 0 {
+                if self.on_finish == AnimationFinishBehavior::PingPong {
+                    self.playing_in_reverse = false;
+                    if last_frame > 0 {
+                        self.current_frame += 1;
+                    }
+                }
+            } else {
+                self.current_frame -= 1;
+            }
+            return;
+        }
+
+        if self.current_frame < last_frame {
+            self.current_frame += 1;
+            return;
+        }
+
+        match self.on_finish {
+            AnimationFinishBehavior::Loop => self.current_frame = 0,
+            AnimationFinishBehavior::PingPong => {
+                self.playing_in_reverse = true;
+                if last_frame > 0 {
+                    self.current_frame -= 1;
+                }
+            }
+            AnimationFinishBehavior::Hold => self.state = PlaybackState::Paused,
+            AnimationFinishBehavior::Despawn => {
+                self.state = PlaybackState::Paused;
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
+
+/// Advances every [`SpriteSheetAnimation`] by the elapsed frame time and writes the resulting
+/// frame into the entity's [`TextureAtlasSprite::index`]. Registered by the sprite plugin.
+pub fn sprite_sheet_animation_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut SpriteSheetAnimation, &mut TextureAtlasSprite)>,
+) {
+    for (entity, mut animation, mut sprite) in query.iter_mut() {
+        if !animation.is_playing() {
+            continue;
+        }
+
+        animation.timer += time.delta_seconds();
+
+        while animation.timer >= animation.frame_duration {
+            animation.timer -= animation.frame_duration;
+            animation.advance(&mut commands, entity);
+        }
+
+        sprite.index = animation.current_frame();
+    }
+}
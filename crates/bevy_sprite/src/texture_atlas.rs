@@ -23,6 +23,9 @@ pub struct TextureAtlas {
     pub textures: Vec<Rect>,
     #[render_resources(ignore)]
     pub texture_handles: Option<HashMap<Handle<Texture>, usize>>,
+    /// Maps authored frame names (e.g. from an atlas descriptor file) to their index in `textures`
+    #[render_resources(ignore)]
+    pub texture_names: Option<HashMap<String, usize>>,
 }
 
 #[derive(Debug, RenderResources, RenderResource)]
@@ -94,6 +97,7 @@ impl TextureAtlas {
             texture,
             size: dimensions,
             texture_handles: None,
+            texture_names: None,
             textures: Vec::new(),
         }
     }
@@ -151,6 +155,7 @@ impl TextureAtlas {
             textures: sprites,
             texture,
             texture_handles: None,
+            texture_names: None,
         }
     }
 
@@ -178,4 +183,27 @@ impl TextureAtlas {
             .as_ref()
             .and_then(|texture_handles| texture_handles.get(texture).cloned())
     }
+
+    /// Returns the index of the texture with the given authored frame `name`, for atlases
+    /// loaded from a descriptor file that names its frames
+    pub fn get_texture_index_by_name(&self, name: &str) -> Option<usize> {
+        self.texture_names
+            .as_ref()
+            .and_then(|texture_names| texture_names.get(name).cloned())
+    }
+
+    /// Returns the pixel-space `Rect` of the texture at `index`, within the atlas
+    pub fn texture_rect(&self, index: usize) -> Option<Rect> {
+        self.textures.get(index).cloned()
+    }
+
+    /// Returns the normalized `[0, 1]` UV `Rect` of the texture at `index`, for use in meshes
+    /// and shaders that sample the atlas texture directly
+    pub fn texture_uv(&self, index: usize) -> Option<Rect> {
+        let rect = self.textures.get(index)?;
+        Some(Rect {
+            min: rect.min / self.size,
+            max: rect.max / self.size,
+        })
+    }
 }
@@ -0,0 +1,251 @@
+use crate::{Rect, TextureAtlas};
+use bevy_asset::{Assets, Handle};
+use bevy_math::Vec2;
+use bevy_render::texture::{Texture, TextureFormat};
+use bevy_utils::HashMap;
+use thiserror::Error;
+
+/// An error produced by [`TextureAtlasBuilder`]
+#[derive(Debug, Error)]
+pub enum TextureAtlasBuilderError {
+    #[error("could not pack all of the textures into an atlas within the max size")]
+    NotEnoughSpace,
+    #[error("a texture handle was registered but could not be found in `Assets<Texture>`")]
+    MissingTexture(Handle<Texture>),
+    #[error("texture is larger than the builder's max_size")]
+    TextureTooLarge { handle: Handle<Texture>, size: Vec2 },
+    #[error("texture has a zero width or height and cannot be packed")]
+    EmptyTexture(Handle<Texture>),
+    #[error("texture format {format:?} is not supported by TextureAtlasBuilder, only Rgba8UnormSrgb can be packed")]
+    UnsupportedFormat {
+        handle: Handle<Texture>,
+        format: TextureFormat,
+    },
+}
+
+/// The dimensions of a texture that has been registered with [`TextureAtlasBuilder::add_texture`],
+/// kept separate from its pixel data until [`TextureAtlasBuilder::finish`] blits it into the atlas
+struct TextureHandleSize {
+    handle: Handle<Texture>,
+    width: u32,
+    height: u32,
+}
+
+/// A horizontal strip of the atlas that textures are placed into left-to-right, used by the
+/// shelf (row) packing algorithm in [`TextureAtlasBuilder::pack`]
+struct Shelf {
+    y: u32,
+    height: u32,
+    x_cursor: u32,
+}
+
+/// How close two texture heights need to be for them to share a [`Shelf`]
+const SHELF_HEIGHT_TOLERANCE: u32 = 2;
+
+/// Packs individual textures into a single newly-allocated [`Texture`] at runtime, producing a
+/// [`TextureAtlas`] with its `textures` rects and `texture_handles` map filled in.
+///
+/// Textures are registered with [`add_texture`](Self::add_texture), then packed and blitted into
+/// the atlas with [`finish`](Self::finish). Packing uses a shelf algorithm: textures are sorted
+/// by descending height and placed on the first shelf with enough height and width remaining,
+/// opening a new shelf otherwise. If the textures do not fit, the atlas size is doubled (up to
+/// `max_size`) and packing is retried.
+pub struct TextureAtlasBuilder {
+    textures: Vec<TextureHandleSize>,
+    texture_handles: HashMap<Handle<Texture>, usize>,
+    /// The size the packer starts from before doubling to fit all of the registered textures
+    pub initial_size: Vec2,
+    /// The largest size the packer is allowed to grow the atlas to
+    pub max_size: Vec2,
+}
+
+impl Default for TextureAtlasBuilder {
+    fn default() -> Self {
+        Self {
+            textures: Vec::new(),
+            texture_handles: HashMap::default(),
+            initial_size: Vec2::new(256.0, 256.0),
+            max_size: Vec2::new(2048.0, 2048.0),
+        }
+    }
+}
+
+impl TextureAtlasBuilder {
+    pub fn new(initial_size: Vec2, max_size: Vec2) -> Self {
+        Self {
+            initial_size: initial_size.min(max_size),
+            max_size,
+            ..Default::default()
+        }
+    }
+
+    /// Registers a texture with the builder, recording its dimensions. The texture's pixel data
+    /// is read again from `Assets<Texture>` when [`finish`](Self::finish) blits it into the atlas.
+    pub fn add_texture(&mut self, texture_handle: Handle<Texture>, texture: &Texture) {
+        self.texture_handles
+            .insert(texture_handle.clone_weak(), self.textures.len());
+        self.textures.push(TextureHandleSize {
+            handle: texture_handle,
+            width: texture.size.x as u32,
+            height: texture.size.y as u32,
+        });
+    }
+
+    /// Attempts to place every texture into shelves of `atlas_width` x `atlas_height`, returning
+    /// `None` if they do not all fit at this size.
+    fn try_pack(
+        textures: &[TextureHandleSize],
+        order: &[usize],
+        atlas_width: u32,
+        atlas_height: u32,
+    ) -> Option<Vec<Rect>> {
+        let mut rects = vec![
+            Rect {
+                min: Vec2::ZERO,
+                max: Vec2::ZERO,
+            };
+            textures.len()
+        ];
+        let mut shelves: Vec<Shelf> = Vec::new();
+
+        for &index in order {
+            let texture = &textures[index];
+
+            let shelf = shelves.iter_mut().find(|shelf| {
+                texture.height <= shelf.height
+                    && shelf.height - texture.height <= SHELF_HEIGHT_TOLERANCE
+                    && shelf.x_cursor + texture.width <= atlas_width
+            });
+
+            let (x, y) = if let Some(shelf) = shelf {
+                let x = shelf.x_cursor;
+                shelf.x_cursor += texture.width;
+                (x, shelf.y)
+            } else {
+                let y: u32 = shelves.iter().map(|shelf| shelf.height).sum();
+                if texture.width > atlas_width || y + texture.height > atlas_height {
+                    return None;
+                }
+                shelves.push(Shelf {
+                    y,
+                    height: texture.height,
+                    x_cursor: texture.width,
+                });
+                (0, y)
+            };
+
+            rects[index] = Rect {
+                min: Vec2::new(x as f32, y as f32),
+                max: Vec2::new((x + texture.width) as f32, (y + texture.height) as f32),
+            };
+        }
+
+        Some(rects)
+    }
+
+    /// Packs every registered texture into shelves, doubling the atlas size until everything
+    /// fits or `max_size` is reached.
+    fn pack(&self) -> Result<(Vec2, Vec<Rect>), TextureAtlasBuilderError> {
+        let max_width = self.max_size.x as u32;
+        let max_height = self.max_size.y as u32;
+
+        for texture in &self.textures {
+            if texture.width == 0 || texture.height == 0 {
+                return Err(TextureAtlasBuilderError::EmptyTexture(
+                    texture.handle.clone_weak(),
+                ));
+            }
+
+            if texture.width > max_width || texture.height > max_height {
+                return Err(TextureAtlasBuilderError::TextureTooLarge {
+                    handle: texture.handle.clone_weak(),
+                    size: Vec2::new(texture.width as f32, texture.height as f32),
+                });
+            }
+        }
+
+        let mut order: Vec<usize> = (0..self.textures.len()).collect();
+        order.sort_by_key(|&index| std::cmp::Reverse(self.textures[index].height));
+
+        let initial_size = self.initial_size.min(self.max_size);
+        let mut atlas_width = (initial_size.x as u32).max(1);
+        let mut atlas_height = (initial_size.y as u32).max(1);
+
+        loop {
+            if let Some(rects) = Self::try_pack(&self.textures, &order, atlas_width, atlas_height)
+            {
+                return Ok((Vec2::new(atlas_width as f32, atlas_height as f32), rects));
+            }
+
+            if atlas_width >= max_width && atlas_height >= max_height {
+                return Err(TextureAtlasBuilderError::NotEnoughSpace);
+            }
+
+            if atlas_height < max_height && (atlas_height <= atlas_width || atlas_width >= max_width)
+            {
+                atlas_height = (atlas_height * 2).min(max_height);
+            } else {
+                atlas_width = (atlas_width * 2).min(max_width);
+            }
+        }
+    }
+
+    /// Copies `source`'s pixel data row-by-row into `atlas_texture` at the position described by
+    /// `rect`. Callers must ensure `source` is `TextureFormat::Rgba8UnormSrgb` first.
+    fn blit(atlas_texture: &mut Texture, rect: &Rect, source: &Texture) {
+        const BYTES_PER_PIXEL: usize = 4;
+
+        let atlas_width = atlas_texture.size.x as usize;
+        let rect_x = rect.min.x as usize;
+        let rect_y = rect.min.y as usize;
+        let width = (rect.max.x - rect.min.x) as usize;
+        let height = (rect.max.y - rect.min.y) as usize;
+
+        for y in 0..height {
+            let src_start = y * width * BYTES_PER_PIXEL;
+            let src_row = &source.data[src_start..src_start + width * BYTES_PER_PIXEL];
+
+            let dst_start = ((rect_y + y) * atlas_width + rect_x) * BYTES_PER_PIXEL;
+            let dst_row = &mut atlas_texture.data[dst_start..dst_start + width * BYTES_PER_PIXEL];
+
+            dst_row.copy_from_slice(src_row);
+        }
+    }
+
+    /// Packs the registered textures into a single newly-allocated [`Texture`] and returns the
+    /// resulting [`TextureAtlas`]
+    pub fn finish(
+        self,
+        textures: &mut Assets<Texture>,
+    ) -> Result<TextureAtlas, TextureAtlasBuilderError> {
+        let (atlas_size, rects) = self.pack()?;
+
+        let mut atlas_texture =
+            Texture::new_fill(atlas_size, &[0, 0, 0, 0], TextureFormat::Rgba8UnormSrgb);
+
+        for (rect, source) in rects.iter().zip(self.textures.iter()) {
+            let source_texture = textures
+                .get(&source.handle)
+                .ok_or_else(|| TextureAtlasBuilderError::MissingTexture(source.handle.clone_weak()))?;
+
+            if source_texture.format != TextureFormat::Rgba8UnormSrgb {
+                return Err(TextureAtlasBuilderError::UnsupportedFormat {
+                    handle: source.handle.clone_weak(),
+                    format: source_texture.format,
+                });
+            }
+
+            Self::blit(&mut atlas_texture, rect, source_texture);
+        }
+
+        let atlas_texture_handle = textures.add(atlas_texture);
+
+        Ok(TextureAtlas {
+            texture: atlas_texture_handle,
+            size: atlas_size,
+            textures: rects,
+            texture_handles: Some(self.texture_handles),
+            texture_names: None,
+        })
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{Rect, TextureAtlas};
+use anyhow::{bail, Result};
+use bevy_asset::{AssetLoader, AssetPath, BoxedFuture, Handle, LoadContext, LoadedAsset};
+use bevy_math::Vec2;
+use bevy_render::texture::Texture;
+use bevy_utils::HashMap;
+use serde::Deserialize;
+use std::path::Path;
+
+/// The pixel rect of a single named frame within a [`TextureAtlasDescriptor`]. Additional fields
+/// such as `trim` or `pivot` authored by a packing tool are accepted but currently ignored.
+#[derive(Debug, Deserialize)]
+struct AtlasFrameDescriptor {
+    x: f32,
+    y: f32,
+    w: f32,
+    h: f32,
+}
+
+/// The overall pixel dimensions of the packed sheet, as authored by the packing tool
+#[derive(Debug, Deserialize)]
+struct AtlasSizeDescriptor {
+    w: f32,
+    h: f32,
+}
+
+/// The on-disk format for a non-uniform, artist-packed [`TextureAtlas`]: the path to the packed
+/// texture, relative to the descriptor file, the sheet's overall `size`, and a map of named
+/// frames and their pixel rects
+#[derive(Debug, Deserialize)]
+struct TextureAtlasDescriptor {
+    texture: String,
+    size: AtlasSizeDescriptor,
+    frames: HashMap<String, AtlasFrameDescriptor>,
+}
+
+/// Loads [`TextureAtlas`] assets from a JSON descriptor listing named frames and their pixel
+/// rects, for sprite sheets that were packed by an external tool rather than a uniform grid
+#[derive(Debug, Default)]
+pub struct TextureAtlasLoader;
+
+impl AssetLoader for TextureAtlasLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let descriptor: TextureAtlasDescriptor = serde_json::from_slice(bytes)?;
+
+            if descriptor.size.w <= 0.0 || descriptor.size.h <= 0.0 {
+                bail!(
+                    "atlas descriptor size must be positive, got {}x{}",
+                    descriptor.size.w,
+                    descriptor.size.h
+                );
+            }
+
+            let texture_path = load_context
+                .path()
+                .parent()
+                .unwrap_or_else(|| Path::new(""))
+                .join(&descriptor.texture);
+            let texture_handle: Handle<Texture> =
+                load_context.get_handle(AssetPath::new(texture_path, None));
+
+            let mut textures = Vec::with_capacity(descriptor.frames.len());
+            let mut texture_names = HashMap::default();
+            for (name, frame) in descriptor.frames {
+                if frame.w <= 0.0 || frame.h <= 0.0 {
+                    bail!(
+                        "atlas frame '{}' must have a positive size, got {}x{}",
+                        name,
+                        frame.w,
+                        frame.h
+                    );
+                }
+
+                texture_names.insert(name, textures.len());
+                textures.push(Rect {
+                    min: Vec2::new(frame.x, frame.y),
+                    max: Vec2::new(frame.x + frame.w, frame.y + frame.h),
+                });
+            }
+
+            let atlas = TextureAtlas {
+                texture: texture_handle.clone(),
+                size: Vec2::new(descriptor.size.w, descriptor.size.h),
+                textures,
+                texture_handles: None,
+                texture_names: Some(texture_names),
+            };
+
+            load_context
+                .set_default_asset(LoadedAsset::new(atlas).with_dependency(texture_handle.into()));
+
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["atlas.json"]
+    }
+}